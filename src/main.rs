@@ -4,11 +4,22 @@ mod type1 {
     pub mod sets {
         pub mod t1mf_gaussian;
         pub mod t1mf_discretised;  // Ensure this file is named t1mf_discretised.rs
+        pub mod norms;
+        pub mod defuzzifier;
+        pub mod it2mf_interval;
+        pub mod t1mf_triangular;
+        pub mod t1mf_trapezoidal;
+        pub mod t1mf_singleton;
+        pub mod t1mf_sigmoid;
+        pub mod t1mf_s_shape;
+        pub mod t1mf_z_shape;
     }
+    pub mod inference;
+    pub mod type_reduction;
 }
 
 use type1::sets::t1mf_discretised::T1MFDiscretised;
-use type1::sets::t1mf_gaussian::Tuple;
+use type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
 
 fn main() {
     println!("--- Testing Discretised Membership Function ---");
@@ -0,0 +1,230 @@
+// src/type1/inference.rs
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use crate::type1::sets::norms::{MaxSNorm, MinTNorm, TNorm};
+use crate::type1::sets::t1mf_discretised::T1MFDiscretised;
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// How many points a consequent membership function is sampled at before it is
+/// clipped/scaled and aggregated.
+const DEFAULT_DISCRETISATION_RESOLUTION: usize = 100;
+
+/// A named linguistic term for one input variable, e.g. `("temperature", "cold")`.
+pub struct Antecedent {
+    variable: String,
+    term: String,
+}
+
+impl Antecedent {
+    pub fn new(variable: &str, term: &str) -> Self {
+        Self {
+            variable: variable.to_string(),
+            term: term.to_string(),
+        }
+    }
+}
+
+/// How a rule's firing strength is applied to its consequent membership function.
+#[derive(Clone, Copy)]
+pub enum ConsequentMethod {
+    /// Clip the consequent at the firing strength (Mamdani min-implication).
+    Clip,
+    /// Scale the consequent by the firing strength (Mamdani product-implication).
+    Scale,
+}
+
+/// A named linguistic term for one output variable, combined with a rule's firing
+/// strength via `ConsequentMethod`.
+pub struct Consequent {
+    variable: String,
+    term: String,
+    method: ConsequentMethod,
+}
+
+impl Consequent {
+    pub fn new(variable: &str, term: &str, method: ConsequentMethod) -> Self {
+        Self {
+            variable: variable.to_string(),
+            term: term.to_string(),
+            method,
+        }
+    }
+}
+
+/// One Mamdani rule: `IF antecedent_1 AND antecedent_2 ... THEN consequent`.
+/// Antecedents are combined with the system's conjunction t-norm.
+pub struct Rule {
+    antecedents: Vec<Antecedent>,
+    consequent: Consequent,
+}
+
+impl Rule {
+    pub fn new(antecedents: Vec<Antecedent>, consequent: Consequent) -> Self {
+        Self {
+            antecedents,
+            consequent,
+        }
+    }
+}
+
+/// A Mamdani fuzzy inference system built from named `T1MFPrototype` input/output
+/// terms. Any membership function type (Gaussian, triangular, trapezoidal, ...) can
+/// be registered as an input, and any with finite support as an output, since both
+/// input matching and consequent clipping/scaling go through the trait's
+/// `get_fs`/`get_support`.
+///
+/// Register input and output variables with `add_input_mf`/`add_output_mf`, add rules
+/// with `add_rule`, then call `evaluate` with a map of crisp inputs to get a map of
+/// crisp, defuzzified outputs.
+pub struct FuzzyInferenceSystem {
+    input_sets: HashMap<String, HashMap<String, Box<dyn T1MFPrototype>>>,
+    output_sets: HashMap<String, HashMap<String, Box<dyn T1MFPrototype>>>,
+    rules: Vec<Rule>,
+    conjunction: Box<dyn TNorm>,
+}
+
+impl FuzzyInferenceSystem {
+    /// Creates an empty inference system using the minimum t-norm to combine
+    /// antecedents within a rule.
+    pub fn new() -> Self {
+        Self {
+            input_sets: HashMap::new(),
+            output_sets: HashMap::new(),
+            rules: Vec::new(),
+            conjunction: Box::new(MinTNorm),
+        }
+    }
+
+    /// Registers a named membership function for an input variable.
+    pub fn add_input_mf(&mut self, variable: &str, term: &str, mf: impl T1MFPrototype + 'static) {
+        self.input_sets
+            .entry(variable.to_string())
+            .or_default()
+            .insert(term.to_string(), Box::new(mf));
+    }
+
+    /// Registers a named membership function for an output variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mf`'s support is not finite: consequents are sampled across their
+    /// support when a rule fires, which requires a bounded range (shoulder sets like
+    /// `T1MFSigmoid`, `T1MFSShape` or `T1MFZShape` cannot be used directly as outputs).
+    pub fn add_output_mf(&mut self, variable: &str, term: &str, mf: impl T1MFPrototype + 'static) {
+        let support = mf.get_support();
+        assert!(
+            support.left.is_finite() && support.right.is_finite(),
+            "output term '{}.{}' has unbounded support [{}, {}]; consequents must have finite support",
+            variable,
+            term,
+            support.left,
+            support.right
+        );
+        self.output_sets
+            .entry(variable.to_string())
+            .or_default()
+            .insert(term.to_string(), Box::new(mf));
+    }
+
+    /// Adds a rule to the system.
+    pub fn add_rule(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    /// Evaluates every rule against `inputs`, aggregates the clipped/scaled consequent
+    /// for each output variable with the maximum s-norm, and returns each output
+    /// variable's defuzzified centroid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rule references a variable/term that was never registered, or if
+    /// `inputs` is missing a crisp value for one of the rule's antecedent variables.
+    pub fn evaluate(&self, inputs: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let mut aggregated: HashMap<String, T1MFDiscretised> = HashMap::new();
+
+        for rule in &self.rules {
+            let firing_strength = rule.antecedents.iter().fold(1.0_f64, |acc, antecedent| {
+                let mf = self
+                    .input_sets
+                    .get(&antecedent.variable)
+                    .and_then(|terms| terms.get(&antecedent.term))
+                    .unwrap_or_else(|| panic!("unknown input term '{}.{}'", antecedent.variable, antecedent.term));
+                let x = *inputs
+                    .get(&antecedent.variable)
+                    .unwrap_or_else(|| panic!("missing crisp input for variable '{}'", antecedent.variable));
+                self.conjunction.apply(acc, mf.get_fs(x))
+            });
+
+            let consequent_mf = self
+                .output_sets
+                .get(&rule.consequent.variable)
+                .and_then(|terms| terms.get(&rule.consequent.term))
+                .unwrap_or_else(|| panic!("unknown output term '{}.{}'", rule.consequent.variable, rule.consequent.term));
+
+            let clipped = clip_or_scale(consequent_mf.as_ref(), firing_strength, rule.consequent.method);
+
+            aggregated
+                .entry(rule.consequent.variable.clone())
+                .and_modify(|existing| *existing = existing.union_with(&clipped, &MaxSNorm))
+                .or_insert(clipped);
+        }
+
+        aggregated
+            .iter_mut()
+            .map(|(variable, mf)| (variable.clone(), mf.get_defuzzified_centroid()))
+            .collect()
+    }
+}
+
+/// Samples `mf` across its support, applies the firing strength via clipping or
+/// scaling at every sampled point, and returns the result as a discretised set ready
+/// for aggregation with other fired rules.
+fn clip_or_scale(mf: &dyn T1MFPrototype, firing_strength: f64, method: ConsequentMethod) -> T1MFDiscretised {
+    let support = mf.get_support();
+    let step = (support.right - support.left) / ((DEFAULT_DISCRETISATION_RESOLUTION - 1) as f64);
+    let mut points = Vec::with_capacity(DEFAULT_DISCRETISATION_RESOLUTION);
+    let mut x = support.left;
+    for _ in 0..DEFAULT_DISCRETISATION_RESOLUTION {
+        let y = match method {
+            ConsequentMethod::Clip => mf.get_fs(x).min(firing_strength),
+            ConsequentMethod::Scale => mf.get_fs(x) * firing_strength,
+        };
+        points.push(Tuple::new(y, x));
+        x += step;
+    }
+    T1MFDiscretised::new(format!("{}-fired", mf.name()), Some(points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type1::sets::t1mf_sigmoid::T1MFSigmoid;
+    use crate::type1::sets::t1mf_triangular::T1MFTriangular;
+
+    #[test]
+    #[should_panic(expected = "unbounded support")]
+    fn add_output_mf_rejects_an_unbounded_support() {
+        let mut system = FuzzyInferenceSystem::new();
+        system.add_output_mf("fan", "fast", T1MFSigmoid::new("fast".to_string(), 1.0, 0.0));
+    }
+
+    #[test]
+    fn evaluate_fires_a_single_rule_and_defuzzifies_to_the_symmetric_peak() {
+        let mut system = FuzzyInferenceSystem::new();
+        system.add_input_mf("temp", "hot", T1MFTriangular::new("hot".to_string(), 20.0, 30.0, 40.0));
+        system.add_output_mf("fan", "fast", T1MFTriangular::new("fast".to_string(), 0.0, 50.0, 100.0));
+        system.add_rule(Rule::new(
+            vec![Antecedent::new("temp", "hot")],
+            Consequent::new("fan", "fast", ConsequentMethod::Clip),
+        ));
+
+        let mut inputs = HashMap::new();
+        inputs.insert("temp".to_string(), 30.0);
+
+        let outputs = system.evaluate(&inputs);
+        let fan = *outputs.get("fan").expect("fan output missing");
+        assert!((fan - 50.0).abs() < 1.0, "expected fan speed near the symmetric peak, got {}", fan);
+    }
+}
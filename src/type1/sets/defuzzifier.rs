@@ -0,0 +1,242 @@
+// src/type1/sets/defuzzifier.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::Tuple;
+
+/// Defuzzification strategies for turning a discretised membership function into a
+/// single crisp value. All variants operate on the sorted discretised points;
+/// `Centroid` and `Bisector` treat membership as piecewise linear between adjacent
+/// points, while the other variants look only at the points themselves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Defuzzifier {
+    /// `∫x·μ dx / ∫μ dx`, integrated via the trapezoid rule.
+    Centroid,
+    /// The x that splits the area under the membership curve into two equal halves.
+    Bisector,
+    /// The mean of the x-values at which membership is maximal.
+    MeanOfMaximum,
+    /// The smallest x-value at which membership is maximal.
+    SmallestOfMaximum,
+    /// The largest x-value at which membership is maximal.
+    LargestOfMaximum,
+    /// The height method `Σ x_i·μ_i / Σ μ_i`, summing over points rather than
+    /// integrating between them.
+    WeightedAverage,
+}
+
+impl Defuzzifier {
+    /// Defuzzifies `points` (sorted by x, i.e. by `right`) using this method.
+    pub fn apply(self, points: &[Tuple]) -> f64 {
+        match self {
+            Defuzzifier::Centroid => centroid(points),
+            Defuzzifier::Bisector => bisector(points),
+            Defuzzifier::MeanOfMaximum => mean_of_maximum(points),
+            Defuzzifier::SmallestOfMaximum => smallest_of_maximum(points),
+            Defuzzifier::LargestOfMaximum => largest_of_maximum(points),
+            Defuzzifier::WeightedAverage => weighted_average(points),
+        }
+    }
+}
+
+/// Returns `∫μ dx`, the trapezoidal area under the membership curve.
+pub fn total_area(points: &[Tuple]) -> f64 {
+    points.windows(2).map(|w| trapezoid_area(&w[0], &w[1])).sum()
+}
+
+fn trapezoid_area(a: &Tuple, b: &Tuple) -> f64 {
+    0.5 * (a.left + b.left) * (b.right - a.right)
+}
+
+/// Returns `∫x·μ dx`, integrated exactly via the closed form for a linear `μ(x)`
+/// between each adjacent pair of points.
+fn weighted_area(points: &[Tuple]) -> f64 {
+    points.windows(2).map(|w| weighted_trapezoid_area(&w[0], &w[1])).sum()
+}
+
+/// `∫x0^x1 x·y(x) dx` for `y` linear between `(x0, y0)` and `(x1, y1)`.
+fn weighted_trapezoid_area(a: &Tuple, b: &Tuple) -> f64 {
+    let (x0, y0) = (a.right, a.left);
+    let (x1, y1) = (b.right, b.left);
+    (x1 - x0) / 6.0 * (x0 * (2.0 * y0 + y1) + x1 * (y0 + 2.0 * y1))
+}
+
+/// `∫x·μ dx / ∫μ dx`, integrated via the trapezoid rule so the result is correct on
+/// irregular grids rather than biased by uneven x-spacing.
+pub fn centroid(points: &[Tuple]) -> f64 {
+    if points.len() < 2 {
+        return points.first().map(|p| p.right).unwrap_or(0.0);
+    }
+    let denominator = total_area(points);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        weighted_area(points) / denominator
+    }
+}
+
+/// The x that splits the area under the membership curve into two equal halves.
+pub fn bisector(points: &[Tuple]) -> f64 {
+    if points.len() < 2 {
+        return points.first().map(|p| p.right).unwrap_or(0.0);
+    }
+    let total = total_area(points);
+    if total == 0.0 {
+        return (points.first().unwrap().right + points.last().unwrap().right) / 2.0;
+    }
+    let target = total / 2.0;
+    let mut accumulated = 0.0;
+    for w in points.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        let segment_area = trapezoid_area(a, b);
+        if accumulated + segment_area >= target {
+            return solve_bisector_x(a, b, target - accumulated);
+        }
+        accumulated += segment_area;
+    }
+    points.last().unwrap().right
+}
+
+/// Solves for the x within `[a.right, b.right]` at which the accumulated area since
+/// `a` first reaches `remaining_area`, given `μ` linear between `a` and `b`.
+fn solve_bisector_x(a: &Tuple, b: &Tuple, remaining_area: f64) -> f64 {
+    let (x0, y0) = (a.right, a.left);
+    let (x1, y1) = (b.right, b.left);
+    let dx = x1 - x0;
+    if dx <= 0.0 {
+        return x0;
+    }
+    let slope = (y1 - y0) / dx;
+    if slope.abs() < std::f64::EPSILON {
+        return if y0 == 0.0 { x0 } else { x0 + remaining_area / y0 };
+    }
+    // area(t) = y0*t + 0.5*slope*t^2 = remaining_area, where t = x - x0.
+    let a_coef = 0.5 * slope;
+    let b_coef = y0;
+    let c_coef = -remaining_area;
+    let discriminant = (b_coef * b_coef - 4.0 * a_coef * c_coef).max(0.0);
+    let sqrt_d = discriminant.sqrt();
+    let candidates = [(-b_coef + sqrt_d) / (2.0 * a_coef), (-b_coef - sqrt_d) / (2.0 * a_coef)];
+    let t = candidates
+        .into_iter()
+        .filter(|t| *t >= 0.0 && *t <= dx)
+        .fold(f64::INFINITY, f64::min);
+    x0 + if t.is_finite() { t } else { 0.0 }
+}
+
+fn max_membership(points: &[Tuple]) -> f64 {
+    points.iter().map(|p| p.left).fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// The mean of the x-values at which membership is maximal.
+pub fn mean_of_maximum(points: &[Tuple]) -> f64 {
+    if points.is_empty() {
+        return 0.0;
+    }
+    let max_y = max_membership(points);
+    let xs: Vec<f64> = points
+        .iter()
+        .filter(|p| (p.left - max_y).abs() < std::f64::EPSILON)
+        .map(|p| p.right)
+        .collect();
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+/// The smallest x-value at which membership is maximal.
+pub fn smallest_of_maximum(points: &[Tuple]) -> f64 {
+    if points.is_empty() {
+        return 0.0;
+    }
+    let max_y = max_membership(points);
+    points
+        .iter()
+        .filter(|p| (p.left - max_y).abs() < std::f64::EPSILON)
+        .map(|p| p.right)
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// The largest x-value at which membership is maximal.
+pub fn largest_of_maximum(points: &[Tuple]) -> f64 {
+    if points.is_empty() {
+        return 0.0;
+    }
+    let max_y = max_membership(points);
+    points
+        .iter()
+        .filter(|p| (p.left - max_y).abs() < std::f64::EPSILON)
+        .map(|p| p.right)
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// The height method `Σ x_i·μ_i / Σ μ_i`, summing over points rather than
+/// integrating between them.
+pub fn weighted_average(points: &[Tuple]) -> f64 {
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for p in points {
+        numerator += p.right * p.left;
+        denominator += p.left;
+    }
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A symmetric trapezoid around x = 5: 0 at x=0, 1 at x=4 and x=6, 0 at x=10.
+    fn symmetric_trapezoid() -> Vec<Tuple> {
+        vec![
+            Tuple::new(0.0, 0.0),
+            Tuple::new(1.0, 4.0),
+            Tuple::new(1.0, 6.0),
+            Tuple::new(0.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn centroid_of_a_symmetric_set_is_its_axis_of_symmetry() {
+        assert!((Defuzzifier::Centroid.apply(&symmetric_trapezoid()) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bisector_of_a_symmetric_set_is_its_axis_of_symmetry() {
+        assert!((Defuzzifier::Bisector.apply(&symmetric_trapezoid()) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_of_maximum_averages_the_plateau_endpoints() {
+        assert!((Defuzzifier::MeanOfMaximum.apply(&symmetric_trapezoid()) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smallest_of_maximum_is_the_plateau_left_edge() {
+        assert!((Defuzzifier::SmallestOfMaximum.apply(&symmetric_trapezoid()) - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn largest_of_maximum_is_the_plateau_right_edge() {
+        assert!((Defuzzifier::LargestOfMaximum.apply(&symmetric_trapezoid()) - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weighted_average_of_a_symmetric_set_is_its_axis_of_symmetry() {
+        assert!((Defuzzifier::WeightedAverage.apply(&symmetric_trapezoid()) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn centroid_of_a_single_point_set_is_that_points_x() {
+        let points = vec![Tuple::new(1.0, 7.0)];
+        assert!((centroid(&points) - 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smallest_and_largest_of_maximum_default_to_zero_on_an_empty_set() {
+        let points: Vec<Tuple> = Vec::new();
+        assert_eq!(smallest_of_maximum(&points), 0.0);
+        assert_eq!(largest_of_maximum(&points), 0.0);
+    }
+}
@@ -0,0 +1,140 @@
+// src/type1/sets/it2mf_interval.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+use crate::type1::type_reduction::EnhancedKarnikMendel;
+
+/// An interval type‑2 fuzzy set, represented by its footprint of uncertainty: an
+/// upper and a lower type‑1 membership function bounding the secondary membership
+/// grade at every x. The bounding functions can be any `T1MFPrototype` (Gaussian,
+/// triangular, trapezoidal, ...), not necessarily the same type.
+pub struct IntervalType2MF {
+    name: String,
+    upper: Box<dyn T1MFPrototype>,
+    lower: Box<dyn T1MFPrototype>,
+    discretisation_resolution: usize,
+}
+
+impl IntervalType2MF {
+    /// Creates a new interval type‑2 membership function from an upper and lower
+    /// bounding type‑1 membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upper` or `lower` has unbounded support: type-reduction discretises
+    /// across the combined support, which requires a bounded range (shoulder sets like
+    /// `T1MFSigmoid`, `T1MFSShape` or `T1MFZShape` cannot be used directly as bounds).
+    pub fn new(name: String, upper: impl T1MFPrototype + 'static, lower: impl T1MFPrototype + 'static) -> Self {
+        let assert_finite_support = |label: &str, support: &Tuple| {
+            assert!(
+                support.left.is_finite() && support.right.is_finite(),
+                "{} bound '{}' has unbounded support [{}, {}]; IT2 bounds must have finite support",
+                label,
+                name,
+                support.left,
+                support.right
+            );
+        };
+        assert_finite_support("upper", upper.get_support());
+        assert_finite_support("lower", lower.get_support());
+        Self {
+            name,
+            upper: Box::new(upper),
+            lower: Box::new(lower),
+            discretisation_resolution: 100,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the upper membership grade at `x`.
+    pub fn get_upper_fs(&self, x: f64) -> f64 {
+        self.upper.get_fs(x)
+    }
+
+    /// Returns the lower membership grade at `x`.
+    pub fn get_lower_fs(&self, x: f64) -> f64 {
+        self.lower.get_fs(x)
+    }
+
+    /// Sets the number of points sampled when discretising for type-reduction.
+    pub fn set_discretisation_resolution(&mut self, resolution: usize) {
+        self.discretisation_resolution = resolution;
+    }
+
+    /// Returns the combined support of both bounding membership functions.
+    pub fn get_support(&self) -> Tuple {
+        let upper_support = self.upper.get_support();
+        let lower_support = self.lower.get_support();
+        Tuple::new(
+            upper_support.left.min(lower_support.left),
+            upper_support.right.max(lower_support.right),
+        )
+    }
+
+    /// Samples both bounding membership functions across the combined support and
+    /// returns the sorted x-grid together with the lower/upper membership grades at
+    /// each x, ready to feed into an `EnhancedKarnikMendel` type-reducer.
+    pub fn discretise(&self) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let support = self.get_support();
+        let step = (support.right - support.left) / ((self.discretisation_resolution - 1) as f64);
+        let mut xs = Vec::with_capacity(self.discretisation_resolution);
+        let mut lower = Vec::with_capacity(self.discretisation_resolution);
+        let mut upper = Vec::with_capacity(self.discretisation_resolution);
+        let mut x = support.left;
+        for _ in 0..self.discretisation_resolution {
+            xs.push(x);
+            lower.push(self.get_lower_fs(x));
+            upper.push(self.get_upper_fs(x));
+            x += step;
+        }
+        (xs, lower, upper)
+    }
+
+    /// Type-reduces via the Enhanced Karnik–Mendel algorithm and returns the centroid
+    /// interval `[c_l, c_r]`.
+    pub fn get_centroid_interval(&self) -> Tuple {
+        let (xs, lower, upper) = self.discretise();
+        EnhancedKarnikMendel::new().centroid_interval(&xs, &lower, &upper)
+    }
+
+    /// Returns the crisp defuzzified value `(c_l + c_r) / 2`.
+    pub fn get_defuzzified(&self) -> f64 {
+        let interval = self.get_centroid_interval();
+        (interval.left + interval.right) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type1::sets::t1mf_sigmoid::T1MFSigmoid;
+    use crate::type1::sets::t1mf_triangular::T1MFTriangular;
+
+    #[test]
+    #[should_panic(expected = "unbounded support")]
+    fn new_rejects_bounds_with_unbounded_support() {
+        IntervalType2MF::new(
+            "temp".to_string(),
+            T1MFSigmoid::new("upper".to_string(), 1.0, 0.0),
+            T1MFSigmoid::new("lower".to_string(), 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn discretise_samples_finite_bounds_with_no_nan() {
+        let mf = IntervalType2MF::new(
+            "temp".to_string(),
+            T1MFTriangular::new("upper".to_string(), 0.0, 5.0, 10.0),
+            T1MFTriangular::new("lower".to_string(), 2.0, 5.0, 8.0),
+        );
+
+        let (xs, lower, upper) = mf.discretise();
+        assert!(xs.iter().all(|v| v.is_finite()));
+        assert!(lower.iter().all(|v| v.is_finite()));
+        assert!(upper.iter().all(|v| v.is_finite()));
+        assert!(mf.get_defuzzified().is_finite());
+    }
+}
@@ -0,0 +1,59 @@
+// src/type1/sets/norms.rs
+#![allow(dead_code)]
+
+/// A triangular norm ("t-norm"), used to combine two membership degrees when
+/// computing the intersection of two fuzzy sets.
+pub trait TNorm {
+    fn apply(&self, a: f64, b: f64) -> f64;
+}
+
+/// A triangular co-norm ("s-norm"), used to combine two membership degrees when
+/// computing the union of two fuzzy sets.
+pub trait SNorm {
+    fn apply(&self, a: f64, b: f64) -> f64;
+}
+
+/// The minimum t-norm: `min(a, b)`. The standard (Zadeh) intersection operator.
+pub struct MinTNorm;
+
+impl TNorm for MinTNorm {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        a.min(b)
+    }
+}
+
+/// The algebraic-product t-norm: `a * b`.
+pub struct ProductTNorm;
+
+impl TNorm for ProductTNorm {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        a * b
+    }
+}
+
+/// The maximum s-norm: `max(a, b)`. The standard (Zadeh) union operator.
+pub struct MaxSNorm;
+
+impl SNorm for MaxSNorm {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        a.max(b)
+    }
+}
+
+/// The probabilistic-sum s-norm: `a + b - a * b`.
+pub struct ProbabilisticSumSNorm;
+
+impl SNorm for ProbabilisticSumSNorm {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        a + b - a * b
+    }
+}
+
+/// The bounded-sum s-norm: `min(1, a + b)`.
+pub struct BoundedSumSNorm;
+
+impl SNorm for BoundedSumSNorm {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        (a + b).min(1.0)
+    }
+}
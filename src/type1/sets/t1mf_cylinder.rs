@@ -29,23 +29,6 @@ impl T1MFCylinder {
         }
     }
 
-    /// Returns the membership degree for any input x.
-    pub fn get_fs(&self, _x: f64) -> f64 {
-        self.membership_degree
-    }
-
-    /// Returns an alpha-cut as an `Option<Tuple>`.
-    ///
-    /// If `alpha` is less than or equal to the membership degree, returns the full support;
-    /// otherwise, returns `None`.
-    pub fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
-        if alpha <= self.membership_degree {
-            Some(Tuple::new(f64::NEG_INFINITY, f64::INFINITY))
-        } else {
-            None
-        }
-    }
-
     /// Returns a string representation of the cylindrical membership function.
     pub fn to_string_rep(&self) -> String {
         format!(
@@ -83,5 +66,22 @@ impl T1MFPrototype for T1MFCylinder {
     fn is_right_shoulder(&self) -> bool {
         false
     }
+
+    /// Returns the membership degree for any input x.
+    fn get_fs(&self, _x: f64) -> f64 {
+        self.membership_degree
+    }
+
+    /// Returns an alpha-cut as an `Option<Tuple>`.
+    ///
+    /// If `alpha` is less than or equal to the membership degree, returns the full support;
+    /// otherwise, returns `None`.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= self.membership_degree {
+            Some(Tuple::new(f64::NEG_INFINITY, f64::INFINITY))
+        } else {
+            None
+        }
+    }
 }
 
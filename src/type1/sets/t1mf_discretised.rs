@@ -1,9 +1,14 @@
 #![allow(unused)]  // Suppress warnings for unused code in this module
 
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::type1::sets::defuzzifier::{self, Defuzzifier};
+use crate::type1::sets::norms::{MaxSNorm, MinTNorm, SNorm, TNorm};
 use crate::type1::sets::t1mf_gaussian::{Tuple, T1MFPrototype};
 
 /// A discretised Type‑1 membership function defined by a set of points.
 /// Points are stored as Tuples in (y, x) order.
+#[derive(Clone)]
 pub struct T1MFDiscretised {
     name: String,
     sorted: bool,
@@ -38,23 +43,26 @@ impl T1MFDiscretised {
         };
         if let Some(ps) = points {
             instance.add_points(ps);
-            instance.sort();
         }
         instance
     }
 
-    /// Adds a single point to the discretised set.
+    /// Adds a single point to the discretised set, keeping it sorted so that
+    /// `get_fs`/`get_alpha_cut` can be evaluated without requiring `&mut self`.
     pub fn add_point(&mut self, p: Tuple) {
         self.set.push(p);
         self.sorted = false;
+        self.sort();
     }
 
-    /// Adds multiple points to the discretised set.
+    /// Adds multiple points to the discretised set, keeping it sorted so that
+    /// `get_fs`/`get_alpha_cut` can be evaluated without requiring `&mut self`.
     pub fn add_points(&mut self, ps: Vec<Tuple>) {
         for p in ps {
             self.set.push(p);
         }
         self.sorted = false;
+        self.sort();
     }
 
     /// Returns the current alpha cut discretisation level.
@@ -72,98 +80,6 @@ impl T1MFDiscretised {
         self.set.len()
     }
 
-    /// Returns the membership degree (fuzzy set value) for input `x`.
-    pub fn get_fs(&mut self, x: f64) -> f64 {
-        if self.set.is_empty() {
-            return -1.0;
-        }
-        if self.left_shoulder && x < self.left_shoulder_start {
-            return 1.0;
-        }
-        if self.right_shoulder && x > self.right_shoulder_start {
-            return 1.0;
-        }
-        let supp = self.get_support();
-        if x < supp.left || x > supp.right {
-            return 0.0;
-        }
-        self.sort();
-
-        // Look for the first point whose x (right) value is greater than x.
-        for i in 0..self.set.len() {
-            if self.set[i].right > x {
-                if self.debug {
-                    println!("Element at {} was not contained in discretised set - interpolating.", x);
-                    println!("Index = {}", i);
-                    if i > 0 {
-                        println!("Previous point x = {}", self.set[i - 1].right);
-                    }
-                    println!("Current point x = {}", self.set[i].right);
-                }
-                // If i is 0, we cannot interpolate; return the value at index 0.
-                if i == 0 {
-                    return self.set[i].left;
-                }
-                return self.interpolate(i - 1, x, i);
-            } else if (self.set[i].right - x).abs() < std::f64::EPSILON {
-                return self.set[i].left;
-            }
-        }
-        -1.0
-    }
-
-    /// Returns the x-values where the alpha cut (given by `alpha`) intersects the set.
-    /// For alpha = 0 or 1, special rules apply.
-    pub fn get_alpha_cut(&mut self, alpha: f64) -> Option<Tuple> {
-        if (alpha - 0.0).abs() < std::f64::EPSILON {
-            return Some(self.get_support());
-        }
-        if (alpha - 1.0).abs() < std::f64::EPSILON {
-            let mut left = 0.0;
-            let mut right = 0.0;
-            for p in &self.set {
-                if (p.left - 1.0).abs() < std::f64::EPSILON {
-                    left = p.right;
-                    break;
-                }
-            }
-            for p in self.set.iter().rev() {
-                if (p.left - 1.0).abs() < std::f64::EPSILON {
-                    right = p.right;
-                    break;
-                }
-            }
-            return Some(Tuple::new(left, right));
-        }
-        let supp = self.get_support();
-        let step_size = (supp.right - supp.left) / ((self.alpha_cut_disc_level - 1) as f64);
-        let mut left_val = supp.left;
-        let mut current_step = supp.left;
-        for _ in 0..self.alpha_cut_disc_level {
-            let current = self.get_fs(current_step) - alpha;
-            if current >= 0.0 {
-                left_val = current_step;
-                break;
-            }
-            current_step += step_size;
-        }
-        let mut right_val = supp.right;
-        current_step = supp.right;
-        for _ in 0..self.alpha_cut_disc_level {
-            let current = self.get_fs(current_step) - alpha;
-            if current >= 0.0 {
-                right_val = current_step;
-                break;
-            }
-            current_step -= step_size;
-        }
-        let mut alpha_cut = Tuple::new(left_val, right_val);
-        if (left_val - right_val).abs() < self.alpha_cut_precision_limit {
-            alpha_cut.right = left_val;
-        }
-        Some(alpha_cut)
-    }
-
     /// Interpolates the membership value at x using points at indices `x0` and `x2`.
     pub fn interpolate(&self, x0: usize, x1: f64, x2: usize) -> f64 {
         let numerator = self.set[x2].right - self.set[x0].right;
@@ -303,6 +219,32 @@ impl T1MFDiscretised {
         Ok(format!("Discretised set {} was successfully written to {}", self.name, filename))
     }
 
+    /// Loads a discretised set from a file previously written by `write_to_file` or
+    /// `write_to_file_high_res`.
+    pub fn from_file(name: String, filename: &str) -> Result<T1MFDiscretised, String> {
+        let file = std::fs::File::open(filename)
+            .map_err(|e| format!("Error reading input file {}: {}", filename, e))?;
+        T1MFDiscretised::from_reader(name, file)
+    }
+
+    /// Loads a discretised set by streaming `x,y` lines from `r`, skipping blank
+    /// lines and `#` comments. Reads line by line rather than buffering the whole
+    /// input, so it stays usable on large traces.
+    pub fn from_reader<R: std::io::Read>(name: String, r: R) -> Result<T1MFDiscretised, String> {
+        use std::io::BufRead;
+        let reader = std::io::BufReader::new(r);
+        let mut points = Vec::new();
+        for (line_no, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| format!("Error reading line {}: {}", line_no + 1, e))?;
+            match parse_points(&line) {
+                Ok(Some(point)) => points.push(point),
+                Ok(None) => {}
+                Err(e) => return Err(format!("Line {}: {}", line_no + 1, e)),
+            }
+        }
+        Ok(T1MFDiscretised::new(name, Some(points)))
+    }
+
     /// Sets this discretised set as a left-shoulder set.
     pub fn set_left_shoulder_set(&mut self, shoulder_start: f64) {
         self.left_shoulder = true;
@@ -319,23 +261,135 @@ impl T1MFDiscretised {
 
     /// Computes the defuzzified centroid using the centroid algorithm.
     pub fn get_defuzzified_centroid(&mut self) -> f64 {
-        let mut numerator = 0.0;
-        let mut denominator = 0.0;
-        for p in self.get_points().iter() {
-            numerator += p.right * p.left;
-            denominator += p.left;
-        }
-        if denominator == 0.0 {
-            0.0
-        } else {
-            numerator / denominator
-        }
+        defuzzifier::centroid(self.get_points())
+    }
+
+    /// Defuzzifies this set using the given `Defuzzifier` method.
+    pub fn get_defuzzified(&mut self, method: Defuzzifier) -> f64 {
+        method.apply(self.get_points())
     }
 
     /// Unsupported method: compare_to.
     pub fn compare_to(&self, _other: &dyn T1MFPrototype) -> i32 {
         panic!("Unsupported Function")
     }
+
+    /// Returns the discretised intersection of `self` and `other`, combined with the
+    /// given t-norm (e.g. `MinTNorm` for the standard intersection, `ProductTNorm` for
+    /// the algebraic-product intersection).
+    pub fn intersection_with(&self, other: &T1MFDiscretised, norm: &dyn TNorm) -> T1MFDiscretised {
+        self.combine(other, "&", |a, b| norm.apply(a, b), true)
+    }
+
+    /// Returns the discretised union of `self` and `other`, combined with the given
+    /// s-norm (e.g. `MaxSNorm` for the standard union, `ProbabilisticSumSNorm` or
+    /// `BoundedSumSNorm` for the alternatives).
+    pub fn union_with(&self, other: &T1MFDiscretised, norm: &dyn SNorm) -> T1MFDiscretised {
+        self.combine(other, "|", |a, b| norm.apply(a, b), false)
+    }
+
+    /// Returns the discretised complement of `self`, i.e. `1 - get_fs(x)` at every point
+    /// of the existing x-grid.
+    pub fn complement(&self) -> T1MFDiscretised {
+        let mut source = self.clone();
+        let points: Vec<Tuple> = source
+            .get_points()
+            .iter()
+            .map(|p| Tuple::new(1.0 - p.left, p.right))
+            .collect();
+        let mut result = T1MFDiscretised::new(format!("!{}", self.name), Some(points));
+        // A left-shoulder set is pinned at 1.0 below its shoulder start and 0.0
+        // beyond its finite (right) grid edge. Its complement is 0.0 below the
+        // shoulder start and 1.0 beyond that same grid edge, i.e. a right-shoulder
+        // set pinned at the original's finite edge. Likewise a right-shoulder set's
+        // complement is a left-shoulder set pinned at the original's finite edge.
+        if source.left_shoulder {
+            result.set_right_shoulder_set(source.support.right);
+        }
+        if source.right_shoulder {
+            result.set_left_shoulder_set(source.support.left);
+        }
+        result
+    }
+
+    /// Samples both operands across the sorted union of their x-grids and applies `op`
+    /// at every merged x, producing a new discretised set over the combined support.
+    fn combine(
+        &self,
+        other: &T1MFDiscretised,
+        symbol: &str,
+        op: impl Fn(f64, f64) -> f64,
+        both_shoulders_required: bool,
+    ) -> T1MFDiscretised {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut xs: Vec<f64> = a.get_points().iter().map(|p| p.right).collect();
+        xs.extend(b.get_points().iter().map(|p| p.right));
+        xs.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        xs.dedup_by(|l, r| (*l - *r).abs() < std::f64::EPSILON);
+
+        let points: Vec<Tuple> = xs
+            .iter()
+            .map(|&x| Tuple::new(op(a.get_fs(x), b.get_fs(x)), x))
+            .collect();
+        let mut result = T1MFDiscretised::new(format!("({} {} {})", self.name, symbol, other.name), Some(points));
+
+        if both_shoulders_required {
+            if a.left_shoulder && b.left_shoulder {
+                result.set_left_shoulder_set(a.left_shoulder_start.min(b.left_shoulder_start));
+            }
+            if a.right_shoulder && b.right_shoulder {
+                result.set_right_shoulder_set(a.right_shoulder_start.max(b.right_shoulder_start));
+            }
+        } else {
+            if a.left_shoulder || b.left_shoulder {
+                let start = match (a.left_shoulder, b.left_shoulder) {
+                    (true, true) => a.left_shoulder_start.max(b.left_shoulder_start),
+                    (true, false) => a.left_shoulder_start,
+                    (false, true) => b.left_shoulder_start,
+                    (false, false) => unreachable!(),
+                };
+                result.set_left_shoulder_set(start);
+            }
+            if a.right_shoulder || b.right_shoulder {
+                let start = match (a.right_shoulder, b.right_shoulder) {
+                    (true, true) => a.right_shoulder_start.min(b.right_shoulder_start),
+                    (true, false) => a.right_shoulder_start,
+                    (false, true) => b.right_shoulder_start,
+                    (false, false) => unreachable!(),
+                };
+                result.set_right_shoulder_set(start);
+            }
+        }
+        result
+    }
+}
+
+impl BitAnd for &T1MFDiscretised {
+    type Output = T1MFDiscretised;
+
+    /// Intersection via the minimum t-norm: `&a & &b`.
+    fn bitand(self, rhs: Self) -> T1MFDiscretised {
+        self.intersection_with(rhs, &MinTNorm)
+    }
+}
+
+impl BitOr for &T1MFDiscretised {
+    type Output = T1MFDiscretised;
+
+    /// Union via the maximum s-norm: `&a | &b`.
+    fn bitor(self, rhs: Self) -> T1MFDiscretised {
+        self.union_with(rhs, &MaxSNorm)
+    }
+}
+
+impl Not for &T1MFDiscretised {
+    type Output = T1MFDiscretised;
+
+    /// Complement: `!&a`.
+    fn not(self) -> T1MFDiscretised {
+        self.complement()
+    }
 }
 
 impl T1MFPrototype for T1MFDiscretised {
@@ -354,5 +408,188 @@ impl T1MFPrototype for T1MFDiscretised {
     fn is_right_shoulder(&self) -> bool {
         self.right_shoulder
     }
+
+    /// Returns the membership degree (fuzzy set value) for input `x`.
+    ///
+    /// Relies on the set being kept sorted by `add_point`/`add_points`, so like
+    /// `get_alpha_cut` (and unlike `get_peak` or `get_points`) this does not need
+    /// `&mut self`.
+    fn get_fs(&self, x: f64) -> f64 {
+        if self.set.is_empty() {
+            return -1.0;
+        }
+        if self.left_shoulder && x < self.left_shoulder_start {
+            return 1.0;
+        }
+        if self.right_shoulder && x > self.right_shoulder_start {
+            return 1.0;
+        }
+        if x < self.support.left || x > self.support.right {
+            return 0.0;
+        }
+
+        // Look for the first point whose x (right) value is greater than x.
+        for i in 0..self.set.len() {
+            if self.set[i].right > x {
+                if self.debug {
+                    println!("Element at {} was not contained in discretised set - interpolating.", x);
+                    println!("Index = {}", i);
+                    if i > 0 {
+                        println!("Previous point x = {}", self.set[i - 1].right);
+                    }
+                    println!("Current point x = {}", self.set[i].right);
+                }
+                // If i is 0, we cannot interpolate; return the value at index 0.
+                if i == 0 {
+                    return self.set[i].left;
+                }
+                return self.interpolate(i - 1, x, i);
+            } else if (self.set[i].right - x).abs() < std::f64::EPSILON {
+                return self.set[i].left;
+            }
+        }
+        -1.0
+    }
+
+    /// Returns the x-values where the alpha cut (given by `alpha`) intersects the set.
+    /// For alpha = 0 or 1, special rules apply.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if (alpha - 0.0).abs() < std::f64::EPSILON {
+            return Some(Tuple::new(self.support.left, self.support.right));
+        }
+        if (alpha - 1.0).abs() < std::f64::EPSILON {
+            let mut left = 0.0;
+            let mut right = 0.0;
+            for p in &self.set {
+                if (p.left - 1.0).abs() < std::f64::EPSILON {
+                    left = p.right;
+                    break;
+                }
+            }
+            for p in self.set.iter().rev() {
+                if (p.left - 1.0).abs() < std::f64::EPSILON {
+                    right = p.right;
+                    break;
+                }
+            }
+            return Some(Tuple::new(left, right));
+        }
+        let supp = &self.support;
+        let step_size = (supp.right - supp.left) / ((self.alpha_cut_disc_level - 1) as f64);
+        let mut left_val = supp.left;
+        let mut current_step = supp.left;
+        for _ in 0..self.alpha_cut_disc_level {
+            let current = self.get_fs(current_step) - alpha;
+            if current >= 0.0 {
+                left_val = current_step;
+                break;
+            }
+            current_step += step_size;
+        }
+        let mut right_val = supp.right;
+        current_step = supp.right;
+        for _ in 0..self.alpha_cut_disc_level {
+            let current = self.get_fs(current_step) - alpha;
+            if current >= 0.0 {
+                right_val = current_step;
+                break;
+            }
+            current_step -= step_size;
+        }
+        let mut alpha_cut = Tuple::new(left_val, right_val);
+        if (left_val - right_val).abs() < self.alpha_cut_precision_limit {
+            alpha_cut.right = left_val;
+        }
+        Some(alpha_cut)
+    }
+}
+
+/// Tokenises one whitespace/comma-separated `x,y` line into a `Tuple` (stored in the
+/// crate's `(y, x)` order). Blank lines and lines starting with `#` (after trimming)
+/// parse to `None`.
+pub fn parse_points(line: &str) -> Result<Option<Tuple>, String> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+    let tokens: Vec<&str> = trimmed
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.len() != 2 {
+        return Err(format!("expected an 'x,y' pair, found '{}'", trimmed));
+    }
+    let x: f64 = tokens[0]
+        .parse()
+        .map_err(|_| format!("invalid x value '{}'", tokens[0]))?;
+    let y: f64 = tokens[1]
+        .parse()
+        .map_err(|_| format!("invalid y value '{}'", tokens[1]))?;
+    Ok(Some(Tuple::new(y, x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_triangles() -> (T1MFDiscretised, T1MFDiscretised) {
+        let a = T1MFDiscretised::new(
+            "A".to_string(),
+            Some(vec![Tuple::new(0.0, 0.0), Tuple::new(1.0, 5.0), Tuple::new(0.0, 10.0)]),
+        );
+        let b = T1MFDiscretised::new(
+            "B".to_string(),
+            Some(vec![Tuple::new(0.0, 0.0), Tuple::new(0.5, 5.0), Tuple::new(1.0, 10.0)]),
+        );
+        (a, b)
+    }
+
+    #[test]
+    fn union_takes_the_max_at_each_merged_point() {
+        let (a, b) = two_triangles();
+        let result = &a | &b;
+        assert!((result.get_fs(5.0) - 1.0).abs() < 1e-9);
+        assert!((result.get_fs(10.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn intersection_takes_the_min_at_each_merged_point() {
+        let (a, b) = two_triangles();
+        let result = &a & &b;
+        assert!((result.get_fs(5.0) - 0.5).abs() < 1e-9);
+        assert!((result.get_fs(10.0) - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn complement_of_a_left_shoulder_set_becomes_a_right_shoulder() {
+        let mut mf = T1MFDiscretised::new(
+            "Shoulder".to_string(),
+            Some(vec![Tuple::new(1.0, 0.0), Tuple::new(0.0, 10.0)]),
+        );
+        mf.set_left_shoulder_set(0.0);
+        let complement = !&mf;
+        assert!((complement.get_fs(-5.0) - 0.0).abs() < 1e-9);
+        assert!((complement.get_fs(15.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn write_to_file_then_from_file_round_trips_the_points() {
+        let mut original = T1MFDiscretised::new(
+            "RoundTrip".to_string(),
+            Some(vec![Tuple::new(0.0, 0.0), Tuple::new(1.0, 5.0), Tuple::new(0.0, 10.0)]),
+        );
+
+        let path = std::env::temp_dir().join(format!("fuzzy_rust_round_trip_{}.csv", std::process::id()));
+        original.write_to_file(path.to_str().unwrap()).expect("write_to_file should succeed");
+
+        let loaded = T1MFDiscretised::from_file("RoundTrip".to_string(), path.to_str().unwrap())
+            .expect("from_file should succeed");
+        std::fs::remove_file(&path).ok();
+
+        assert!((loaded.get_fs(5.0) - 1.0).abs() < 1e-9);
+        assert!((loaded.get_fs(0.0) - 0.0).abs() < 1e-9);
+        assert!((loaded.get_fs(10.0) - 0.0).abs() < 1e-9);
+    }
 }
 
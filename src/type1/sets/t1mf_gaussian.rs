@@ -24,6 +24,11 @@ pub trait T1MFPrototype {
     fn get_support(&self) -> &Tuple;
     fn is_left_shoulder(&self) -> bool;
     fn is_right_shoulder(&self) -> bool;
+    /// Returns the membership degree (fuzzy set value) for input `x`.
+    fn get_fs(&self, x: f64) -> f64;
+    /// Returns the x-interval where membership is at least `alpha`, or `None` if no
+    /// such interval exists for this set.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple>;
 }
 
 /// The Gaussian membership function for Type‑1 fuzzy sets.
@@ -42,21 +47,6 @@ impl T1MFGaussian {
         Self { name, mean, spread, support }
     }
 
-    /// Returns the fuzzy set value for a given x.
-    pub fn get_fs(&self, x: f64) -> f64 {
-        if x >= self.support.left && x <= self.support.right {
-            if self.is_left_shoulder() && x <= self.mean {
-                return 1.0;
-            }
-            if self.is_right_shoulder() && x >= self.mean {
-                return 1.0;
-            }
-            (-0.5 * ((x - self.mean) / self.spread).powi(2)).exp()
-        } else {
-            0.0
-        }
-    }
-
     /// Returns a string representation of the Gaussian membership function.
     pub fn to_string_rep(&self) -> String {
         let mut s = format!(
@@ -89,5 +79,33 @@ impl T1MFPrototype for T1MFGaussian {
     fn is_right_shoulder(&self) -> bool {
         false
     }
+
+    /// Returns the fuzzy set value for a given x.
+    fn get_fs(&self, x: f64) -> f64 {
+        if x >= self.support.left && x <= self.support.right {
+            if self.is_left_shoulder() && x <= self.mean {
+                return 1.0;
+            }
+            if self.is_right_shoulder() && x >= self.mean {
+                return 1.0;
+            }
+            (-0.5 * ((x - self.mean) / self.spread).powi(2)).exp()
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the analytic alpha-cut `[mean - spread*sqrt(-2*ln(alpha)), mean + spread*sqrt(-2*ln(alpha))]`.
+    /// `alpha <= 0` yields the full support; `alpha >= 1` yields the singleton `{mean}`.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(self.support.left, self.support.right));
+        }
+        if alpha >= 1.0 {
+            return Some(Tuple::new(self.mean, self.mean));
+        }
+        let half_width = self.spread * (-2.0 * alpha.ln()).sqrt();
+        Some(Tuple::new(self.mean - half_width, self.mean + half_width))
+    }
 }
 
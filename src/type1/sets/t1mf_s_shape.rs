@@ -0,0 +1,100 @@
+// src/type1/sets/t1mf_s_shape.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// An S-shaped (spline-based) Type‑1 membership function: 0 below `a`, rising through
+/// a pair of quadratic segments that meet at the midpoint `(a + b) / 2`, and 1 at and
+/// above `b` (`a < b`). This is a smooth right-shoulder set.
+pub struct T1MFSShape {
+    name: String,
+    a: f64,
+    b: f64,
+    support: Tuple,
+}
+
+impl T1MFSShape {
+    /// Constructs a new S-shaped membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a < b` does not hold.
+    pub fn new(name: String, a: f64, b: f64) -> Self {
+        assert!(a < b, "S-shape MF requires a < b");
+        Self {
+            name,
+            a,
+            b,
+            support: Tuple::new(a, f64::INFINITY),
+        }
+    }
+
+    /// Returns a string representation of the S-shaped membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!("{} - SShape({}, {})", self.name, self.a, self.b)
+    }
+}
+
+impl T1MFPrototype for T1MFSShape {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        false
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        true
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        let midpoint = (self.a + self.b) / 2.0;
+        if x <= self.a {
+            0.0
+        } else if x <= midpoint {
+            2.0 * ((x - self.a) / (self.b - self.a)).powi(2)
+        } else if x < self.b {
+            1.0 - 2.0 * ((x - self.b) / (self.b - self.a)).powi(2)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the half-open interval `[x_alpha, +inf)` where membership is at least
+    /// `alpha`, inverting whichever quadratic segment `alpha` falls in.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(self.a, f64::INFINITY));
+        }
+        if alpha >= 1.0 {
+            return Some(Tuple::new(self.b, f64::INFINITY));
+        }
+        let width = self.b - self.a;
+        let x_alpha = if alpha <= 0.5 {
+            self.a + width * (alpha / 2.0).sqrt()
+        } else {
+            self.b - width * ((1.0 - alpha) / 2.0).sqrt()
+        };
+        Some(Tuple::new(x_alpha, f64::INFINITY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_at_the_midpoint_starts_at_the_spline_midpoint() {
+        let mf = T1MFSShape::new("s".to_string(), 0.0, 10.0);
+        // get_fs at the midpoint (a + b) / 2 = 5 is exactly 0.5 by construction, so the
+        // alpha = 0.5 cut should start there.
+        let cut = mf.get_alpha_cut(0.5).expect("S-shape alpha-cut is defined for 0 < alpha < 1");
+        assert!((cut.left - 5.0).abs() < 1e-9);
+        assert_eq!(cut.right, f64::INFINITY);
+    }
+}
@@ -0,0 +1,94 @@
+// src/type1/sets/t1mf_sigmoid.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// A sigmoidal Type‑1 membership function: `mu(x) = 1 / (1 + exp(-slope * (x - crossover)))`.
+/// Positive `slope` produces a right-shoulder-like set that saturates at 1 as `x`
+/// grows; negative `slope` produces a left-shoulder-like set that saturates at 1 as
+/// `x` shrinks. Membership never reaches exactly 0 or 1, so the support is the whole
+/// real line.
+pub struct T1MFSigmoid {
+    name: String,
+    slope: f64,
+    crossover: f64,
+    support: Tuple,
+}
+
+impl T1MFSigmoid {
+    /// Constructs a new sigmoidal membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slope` is zero (the function would be constant at 0.5).
+    pub fn new(name: String, slope: f64, crossover: f64) -> Self {
+        assert!(slope != 0.0, "sigmoid MF requires a non-zero slope");
+        Self {
+            name,
+            slope,
+            crossover,
+            support: Tuple::new(f64::NEG_INFINITY, f64::INFINITY),
+        }
+    }
+
+    /// Returns a string representation of the sigmoidal membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!("{} - Sigmoid(slope={}, crossover={})", self.name, self.slope, self.crossover)
+    }
+}
+
+impl T1MFPrototype for T1MFSigmoid {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        self.slope < 0.0
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        self.slope > 0.0
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        1.0 / (1.0 + (-self.slope * (x - self.crossover)).exp())
+    }
+
+    /// Returns the half-open interval on the saturating side of the crossover where
+    /// membership is at least `alpha`. `alpha <= 0` yields the full real line;
+    /// `alpha >= 1` returns `None` since the asymptote is never reached.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(f64::NEG_INFINITY, f64::INFINITY));
+        }
+        if alpha >= 1.0 {
+            return None;
+        }
+        // alpha = 1 / (1 + exp(-slope * (x - crossover)))  =>  x = crossover - ln(1/alpha - 1) / slope
+        let x_alpha = self.crossover - (1.0 / alpha - 1.0).ln() / self.slope;
+        if self.slope > 0.0 {
+            Some(Tuple::new(x_alpha, f64::INFINITY))
+        } else {
+            Some(Tuple::new(f64::NEG_INFINITY, x_alpha))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_at_the_crossover_membership_starts_at_the_crossover_point() {
+        let mf = T1MFSigmoid::new("s".to_string(), 1.0, 0.0);
+        // At x = crossover, get_fs(0.0) = 1 / (1 + exp(0)) = 0.5, so the alpha = 0.5
+        // cut should start exactly at the crossover.
+        let cut = mf.get_alpha_cut(0.5).expect("sigmoid alpha-cut is defined for 0 < alpha < 1");
+        assert!((cut.left - 0.0).abs() < 1e-9);
+        assert_eq!(cut.right, f64::INFINITY);
+    }
+}
@@ -0,0 +1,81 @@
+// src/type1/sets/t1mf_singleton.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// A singleton Type‑1 membership function: membership is 1 at exactly `value` and 0
+/// everywhere else. Commonly used to represent a crisp input as a fuzzy set.
+pub struct T1MFSingleton {
+    name: String,
+    value: f64,
+    support: Tuple,
+}
+
+impl T1MFSingleton {
+    /// Constructs a new singleton membership function at `value`.
+    pub fn new(name: String, value: f64) -> Self {
+        Self {
+            name,
+            value,
+            support: Tuple::new(value, value),
+        }
+    }
+
+    /// Returns a string representation of the singleton membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!("{} - Singleton({})", self.name, self.value)
+    }
+}
+
+impl T1MFPrototype for T1MFSingleton {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        false
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        false
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        if (x - self.value).abs() < std::f64::EPSILON {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 1.0 {
+            Some(Tuple::new(self.value, self.value))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_is_the_singleton_point_for_any_valid_alpha() {
+        let mf = T1MFSingleton::new("s".to_string(), 5.0);
+        let cut = mf.get_alpha_cut(0.3).expect("singleton alpha-cut is defined for alpha <= 1");
+        assert!((cut.left - 5.0).abs() < 1e-9);
+        assert!((cut.right - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn alpha_cut_is_undefined_above_one() {
+        let mf = T1MFSingleton::new("s".to_string(), 5.0);
+        assert!(mf.get_alpha_cut(1.5).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+// src/type1/sets/t1mf_trapezoidal.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// A trapezoidal Type‑1 membership function, rising linearly from `a` to `b`, flat at
+/// 1 between `b` and `c`, then falling linearly to `d` (`a <= b <= c <= d`).
+pub struct T1MFTrapezoidal {
+    name: String,
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    support: Tuple,
+}
+
+impl T1MFTrapezoidal {
+    /// Constructs a new trapezoidal membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a <= b <= c <= d` does not hold.
+    pub fn new(name: String, a: f64, b: f64, c: f64, d: f64) -> Self {
+        assert!(a <= b && b <= c && c <= d, "trapezoidal MF requires a <= b <= c <= d");
+        Self {
+            name,
+            a,
+            b,
+            c,
+            d,
+            support: Tuple::new(a, d),
+        }
+    }
+
+    /// Returns a string representation of the trapezoidal membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!(
+            "{} - Trapezoidal({}, {}, {}, {})",
+            self.name, self.a, self.b, self.c, self.d
+        )
+    }
+}
+
+impl T1MFPrototype for T1MFTrapezoidal {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        false
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        false
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        if x <= self.a || x >= self.d {
+            0.0
+        } else if x < self.b {
+            if self.a == self.b {
+                1.0
+            } else {
+                (x - self.a) / (self.b - self.a)
+            }
+        } else if x <= self.c {
+            1.0
+        } else {
+            (self.d - x) / (self.d - self.c)
+        }
+    }
+
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(self.a, self.d));
+        }
+        if alpha >= 1.0 {
+            return Some(Tuple::new(self.b, self.c));
+        }
+        let left = self.a + alpha * (self.b - self.a);
+        let right = self.d - alpha * (self.d - self.c);
+        Some(Tuple::new(left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_narrows_towards_the_flat_top() {
+        let mf = T1MFTrapezoidal::new("t".to_string(), 0.0, 2.0, 8.0, 10.0);
+        let cut = mf.get_alpha_cut(0.5).expect("trapezoidal alpha-cut is always defined");
+        assert!((cut.left - 1.0).abs() < 1e-9);
+        assert!((cut.right - 9.0).abs() < 1e-9);
+    }
+}
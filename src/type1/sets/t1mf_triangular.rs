@@ -0,0 +1,105 @@
+// src/type1/sets/t1mf_triangular.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// A triangular Type‑1 membership function, rising linearly from `a` to the peak at
+/// `b`, then falling linearly to `c` (`a <= b <= c`).
+pub struct T1MFTriangular {
+    name: String,
+    a: f64,
+    b: f64,
+    c: f64,
+    support: Tuple,
+}
+
+impl T1MFTriangular {
+    /// Constructs a new triangular membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a <= b <= c` does not hold.
+    pub fn new(name: String, a: f64, b: f64, c: f64) -> Self {
+        assert!(a <= b && b <= c, "triangular MF requires a <= b <= c");
+        Self {
+            name,
+            a,
+            b,
+            c,
+            support: Tuple::new(a, c),
+        }
+    }
+
+    /// Returns the x-coordinate of the peak.
+    pub fn get_peak(&self) -> f64 {
+        self.b
+    }
+
+    /// Returns a string representation of the triangular membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!("{} - Triangular({}, {}, {})", self.name, self.a, self.b, self.c)
+    }
+}
+
+impl T1MFPrototype for T1MFTriangular {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        false
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        false
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        if x <= self.a || x >= self.c {
+            0.0
+        } else if x < self.b {
+            if self.a == self.b {
+                1.0
+            } else {
+                (x - self.a) / (self.b - self.a)
+            }
+        } else if x > self.b {
+            if self.b == self.c {
+                1.0
+            } else {
+                (self.c - x) / (self.c - self.b)
+            }
+        } else {
+            1.0
+        }
+    }
+
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(self.a, self.c));
+        }
+        if alpha >= 1.0 {
+            return Some(Tuple::new(self.b, self.b));
+        }
+        let left = self.a + alpha * (self.b - self.a);
+        let right = self.c - alpha * (self.c - self.b);
+        Some(Tuple::new(left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_narrows_symmetrically_around_the_peak() {
+        let mf = T1MFTriangular::new("t".to_string(), 0.0, 5.0, 10.0);
+        let cut = mf.get_alpha_cut(0.5).expect("triangular alpha-cut is always defined");
+        assert!((cut.left - 2.5).abs() < 1e-9);
+        assert!((cut.right - 7.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,101 @@
+// src/type1/sets/t1mf_z_shape.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::{T1MFPrototype, Tuple};
+
+/// A Z-shaped (spline-based) Type‑1 membership function: 1 at and below `a`, falling
+/// through a pair of quadratic segments that meet at the midpoint `(a + b) / 2`, and 0
+/// above `b` (`a < b`). This is the mirror image of `T1MFSShape` and a smooth
+/// left-shoulder set.
+pub struct T1MFZShape {
+    name: String,
+    a: f64,
+    b: f64,
+    support: Tuple,
+}
+
+impl T1MFZShape {
+    /// Constructs a new Z-shaped membership function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a < b` does not hold.
+    pub fn new(name: String, a: f64, b: f64) -> Self {
+        assert!(a < b, "Z-shape MF requires a < b");
+        Self {
+            name,
+            a,
+            b,
+            support: Tuple::new(f64::NEG_INFINITY, b),
+        }
+    }
+
+    /// Returns a string representation of the Z-shaped membership function.
+    pub fn to_string_rep(&self) -> String {
+        format!("{} - ZShape({}, {})", self.name, self.a, self.b)
+    }
+}
+
+impl T1MFPrototype for T1MFZShape {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_support(&self) -> &Tuple {
+        &self.support
+    }
+
+    fn is_left_shoulder(&self) -> bool {
+        true
+    }
+
+    fn is_right_shoulder(&self) -> bool {
+        false
+    }
+
+    fn get_fs(&self, x: f64) -> f64 {
+        let midpoint = (self.a + self.b) / 2.0;
+        if x <= self.a {
+            1.0
+        } else if x <= midpoint {
+            1.0 - 2.0 * ((x - self.a) / (self.b - self.a)).powi(2)
+        } else if x < self.b {
+            2.0 * ((x - self.b) / (self.b - self.a)).powi(2)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the half-open interval `(-inf, x_alpha]` where membership is at least
+    /// `alpha`, inverting whichever quadratic segment `alpha` falls in.
+    fn get_alpha_cut(&self, alpha: f64) -> Option<Tuple> {
+        if alpha <= 0.0 {
+            return Some(Tuple::new(f64::NEG_INFINITY, self.b));
+        }
+        if alpha >= 1.0 {
+            return Some(Tuple::new(f64::NEG_INFINITY, self.a));
+        }
+        let width = self.b - self.a;
+        let x_alpha = if alpha <= 0.5 {
+            self.b - width * (alpha / 2.0).sqrt()
+        } else {
+            self.a + width * ((1.0 - alpha) / 2.0).sqrt()
+        };
+        Some(Tuple::new(f64::NEG_INFINITY, x_alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_cut_at_the_midpoint_ends_at_the_spline_midpoint() {
+        let mf = T1MFZShape::new("z".to_string(), 0.0, 10.0);
+        // get_fs at the midpoint (a + b) / 2 = 5 is exactly 0.5 by construction, so the
+        // alpha = 0.5 cut should end there.
+        let cut = mf.get_alpha_cut(0.5).expect("Z-shape alpha-cut is defined for 0 < alpha < 1");
+        assert_eq!(cut.left, f64::NEG_INFINITY);
+        assert!((cut.right - 5.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,119 @@
+// src/type1/type_reduction.rs
+#![allow(dead_code)]
+
+use crate::type1::sets::t1mf_gaussian::Tuple;
+
+/// The Enhanced Karnik–Mendel (EKM) type-reduction algorithm for interval type‑2
+/// fuzzy sets. Given a sorted x-grid with lower and upper membership grades at each
+/// point, it computes the centroid interval `[c_l, c_r]` of the footprint of
+/// uncertainty.
+pub struct EnhancedKarnikMendel;
+
+impl EnhancedKarnikMendel {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Computes the centroid interval `[c_l, c_r]` for the sampled points `xs` (sorted
+    /// ascending) with lower memberships `lower` and upper memberships `upper`.
+    ///
+    /// `denominators` of zero (an all-zero membership grid) fall back to the midpoint
+    /// of the support.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs`, `lower` and `upper` do not all have the same length.
+    pub fn centroid_interval(&self, xs: &[f64], lower: &[f64], upper: &[f64]) -> Tuple {
+        assert_eq!(xs.len(), lower.len());
+        assert_eq!(xs.len(), upper.len());
+        let c_l = self.reduce(xs, lower, upper, true);
+        let c_r = self.reduce(xs, lower, upper, false);
+        Tuple::new(c_l, c_r)
+    }
+
+    /// Returns the crisp defuzzified value `(c_l + c_r) / 2`.
+    pub fn defuzzify(&self, xs: &[f64], lower: &[f64], upper: &[f64]) -> f64 {
+        let interval = self.centroid_interval(xs, lower, upper);
+        (interval.left + interval.right) / 2.0
+    }
+
+    /// Iteratively computes one endpoint of the centroid interval. `for_left`
+    /// selects which side of the switch point uses the upper membership grade: the
+    /// left endpoint uses `upper` for `i <= k` and `lower` for `i > k`; the right
+    /// endpoint uses the opposite assignment.
+    fn reduce(&self, xs: &[f64], lower: &[f64], upper: &[f64], for_left: bool) -> f64 {
+        let n = xs.len();
+        if n == 0 {
+            return 0.0;
+        }
+        let midpoint = (xs[0] + xs[n - 1]) / 2.0;
+
+        let initial_weights: Vec<f64> = (0..n).map(|i| (lower[i] + upper[i]) / 2.0).collect();
+        let mut c = weighted_centroid(xs, &initial_weights).unwrap_or(midpoint);
+        let mut k = switch_index(xs, c);
+
+        // Bounded by the number of grid points: the switch index can take at most
+        // `n - 1` distinct values, so it must stabilise within that many iterations.
+        for _ in 0..n {
+            let weights: Vec<f64> = (0..n)
+                .map(|i| {
+                    let use_upper = if for_left { i <= k } else { i > k };
+                    if use_upper { upper[i] } else { lower[i] }
+                })
+                .collect();
+            let new_c = weighted_centroid(xs, &weights).unwrap_or(midpoint);
+            let new_k = switch_index(xs, new_c);
+            c = new_c;
+            if new_k == k {
+                break;
+            }
+            k = new_k;
+        }
+        c
+    }
+}
+
+/// Returns `Σ x_i·w_i / Σ w_i`, or `None` if the total weight is zero.
+fn weighted_centroid(xs: &[f64], weights: &[f64]) -> Option<f64> {
+    let denominator: f64 = weights.iter().sum();
+    if denominator == 0.0 {
+        return None;
+    }
+    let numerator: f64 = xs.iter().zip(weights).map(|(x, w)| x * w).sum();
+    Some(numerator / denominator)
+}
+
+/// Returns the index `k` such that `xs[k] <= c < xs[k + 1]`, clamped to the last
+/// valid index if `c` falls outside the grid.
+fn switch_index(xs: &[f64], c: f64) -> usize {
+    let n = xs.len();
+    if n < 2 {
+        return 0;
+    }
+    for i in 0..n - 1 {
+        if xs[i] <= c && c < xs[i + 1] {
+            return i;
+        }
+    }
+    n - 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centroid_interval_matches_hand_computed_result_for_a_two_point_fou() {
+        let ekm = EnhancedKarnikMendel::new();
+        let xs = vec![0.0, 1.0];
+        let lower = vec![0.0, 0.0];
+        let upper = vec![1.0, 1.0];
+
+        // By hand: the switch index is always 0 on a two-point grid, so c_l takes
+        // upper[0]/lower[1] -> weighted centroid (0*1 + 1*0) / (1 + 0) = 0, and c_r
+        // takes lower[0]/upper[1] -> (0*0 + 1*1) / (0 + 1) = 1.
+        let interval = ekm.centroid_interval(&xs, &lower, &upper);
+        assert!((interval.left - 0.0).abs() < 1e-9);
+        assert!((interval.right - 1.0).abs() < 1e-9);
+    }
+}